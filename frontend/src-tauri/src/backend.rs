@@ -1,14 +1,18 @@
 use anyhow::{anyhow, Context, Result};
 use std::fs::{self, create_dir_all, OpenOptions};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Mutex;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use notify::RecursiveMode;
+use notify_debouncer_mini::new_debouncer;
 use tauri::async_runtime::Receiver;
 use tauri::path::BaseDirectory;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
+use tauri_plugin_store::StoreExt;
 
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
@@ -30,12 +34,105 @@ pub struct BackendManager {
     app: AppHandle,
     /// The port the backend is listening on (discovered from port file)
     port: Mutex<Option<u16>>,
+    /// Set while `stop_all`/`Drop` are intentionally tearing the backend down,
+    /// so the supervisor knows not to treat the exit as a crash.
+    shutdown: AtomicBool,
+    /// Consecutive unexpected-exit count, used to size the backoff delay.
+    restart_attempts: AtomicU32,
+    /// When the currently running backend process was (re)spawned.
+    last_spawn: Mutex<Instant>,
+    /// Set while waiting for the backend's `{"event":"bye"}` shutdown ack.
+    bye_ack: Mutex<Option<std::sync::mpsc::Sender<()>>>,
+    /// Base URL of an externally managed backend, if remote mode is configured.
+    /// When set, `start_all`/`stop_all` skip process management entirely.
+    remote_url: Option<String>,
+    /// Current lifecycle state, for `get_backend_status` and the `backend-status` event.
+    status: Mutex<BackendStatus>,
 }
 
 const MAIN_MODULE: &str = "valuecell.server.main";
-const EXIT_COMMAND: &[u8] = b"__EXIT__\n";
 const GRACEFUL_TIMEOUT_SECS: u64 = 3;
 
+/// Maximum number of automatic restarts attempted before giving up
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+
+/// Base delay before the first restart attempt; doubles on each subsequent attempt
+const INITIAL_RESTART_BACKOFF_SECS: u64 = 30;
+
+/// Upper bound on the restart backoff delay, regardless of attempt count
+const MAX_RESTART_BACKOFF_SECS: u64 = 300;
+
+/// How long a restarted backend must stay up before the attempt counter resets
+const RESTART_STABLE_UPTIME_SECS: u64 = 60;
+
+/// Opt-in env var that enables the dev hot-reload watcher; unset in release builds
+const DEV_HOT_RELOAD_ENV: &str = "VALUECELL_DEV_HOT_RELOAD";
+
+/// Debounce window for coalescing bursts of file-change events from the watcher
+const DEV_WATCH_DEBOUNCE_MS: u64 = 500;
+
+/// Env var pointing at an already-running backend (container, SSH tunnel, shared dev
+/// server) instead of a locally spawned sidecar, e.g. `http://127.0.0.1:9000`
+const REMOTE_BACKEND_URL_ENV: &str = "VALUECELL_REMOTE_BACKEND_URL";
+
+/// Settings store file and key used as a GUI-configurable alternative to the env var
+const SETTINGS_STORE_FILE: &str = "settings.json";
+const REMOTE_BACKEND_URL_KEY: &str = "remoteBackendUrl";
+
+/// Timeout for the one-shot reachability probe run against a remote backend on startup
+const REMOTE_PROBE_TIMEOUT_SECS: u64 = 3;
+
+/// Path probed to confirm the backend is actually serving requests, not just bound to
+/// its port
+const HEALTH_CHECK_PATH: &str = "/health";
+
+/// Poll interval while waiting for the health check to return 2xx
+const HEALTH_POLL_MS: u64 = 200;
+
+/// Per-request timeout for a single health-check attempt. Kept separate from
+/// `HEALTH_POLL_MS` so a backend that's slow to answer (DB ping, model load) but
+/// still well within `HEALTH_TIMEOUT_MS` isn't timed out on every single attempt.
+const HEALTH_REQUEST_TIMEOUT_MS: u64 = 2000;
+
+/// Overall timeout for the health-probe readiness stage
+const HEALTH_TIMEOUT_MS: u64 = 30000;
+
+/// Rotate `backend.log` once it exceeds this size
+const LOG_ROTATE_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Number of rotated generations to keep (`backend.log.1` .. `backend.log.N`)
+const LOG_ROTATE_MAX_GENERATIONS: u32 = 5;
+
+/// A single captured backend log line, forwarded to the frontend via the
+/// `backend-log` event so it doesn't have to tail the log file itself.
+#[derive(Clone, serde::Serialize)]
+struct BackendLogLine {
+    level: LogLevel,
+    line: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+/// Backend lifecycle state, surfaced to the frontend via `get_backend_status` and the
+/// `backend-status` event so the UI can show an accurate splash/loading screen and
+/// distinguish "still starting" from "crashed".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackendStatus {
+    Installing,
+    Spawning,
+    WaitingForPort,
+    ProbingHealth,
+    Ready,
+    Failed,
+}
+
 impl BackendManager {
     fn wait_until_terminated(mut rx: Receiver<CommandEvent>) {
         while let Some(event) = rx.blocking_recv() {
@@ -125,16 +222,31 @@ impl BackendManager {
         let pid = process.pid();
         log::info!("Requesting graceful shutdown for process {}", pid);
 
-        if let Err(err) = process.write(EXIT_COMMAND) {
-            log::warn!(
-                "Failed to send shutdown command to process {}: {}",
-                pid, err
-            );
-        } else {
-            log::info!("Exit command written to process {}", pid);
+        let (ack_tx, ack_rx) = std::sync::mpsc::channel();
+        *self.bye_ack.lock().unwrap() = Some(ack_tx);
+
+        match Self::frame_message(&serde_json::json!({ "cmd": "shutdown" })) {
+            Ok(framed) => {
+                if let Err(err) = process.write(&framed) {
+                    log::warn!(
+                        "Failed to send shutdown command to process {}: {}",
+                        pid, err
+                    );
+                } else {
+                    log::info!("Shutdown command sent to process {}", pid);
+                }
+            }
+            Err(err) => log::warn!("Failed to frame shutdown command: {}", err),
         }
 
-        std::thread::sleep(Duration::from_secs(GRACEFUL_TIMEOUT_SECS));
+        match ack_rx.recv_timeout(Duration::from_secs(GRACEFUL_TIMEOUT_SECS)) {
+            Ok(()) => log::info!("Process {} acknowledged shutdown", pid),
+            Err(_) => log::warn!(
+                "Process {} did not acknowledge shutdown in time, escalating",
+                pid
+            ),
+        }
+        *self.bye_ack.lock().unwrap() = None;
 
         log::info!("Sending forceful shutdown to process {}", pid);
         self.kill_descendants_best_effort(pid);
@@ -146,14 +258,103 @@ impl BackendManager {
         }
     }
 
+    /// Frame a control message for the sidecar control protocol: a 4-byte big-endian
+    /// length prefix followed by the UTF-8 JSON body, e.g. `{"cmd":"shutdown"}`,
+    /// `{"cmd":"reload_config"}`, or `{"cmd":"status"}`. This framing only applies to
+    /// the stdin direction (Tauri -> Python); `CommandEvent::Stdout` already hands us
+    /// line-buffered text, so the Python backend's control responses
+    /// (`{"event":"ready","port":...}`, `{"event":"pong"}`, `{"event":"bye"}`) must be
+    /// written as one bare JSON object per stdout line, NOT length-prefixed — see
+    /// `handle_framed_line`.
+    fn frame_message(msg: &serde_json::Value) -> Result<Vec<u8>> {
+        let body = serde_json::to_vec(msg).context("Failed to serialize control message")?;
+        let len = u32::try_from(body.len()).context("Control message too large to frame")?;
+
+        let mut framed = Vec::with_capacity(4 + body.len());
+        framed.extend_from_slice(&len.to_be_bytes());
+        framed.extend_from_slice(&body);
+        Ok(framed)
+    }
+
+    /// Send a framed JSON control message to the running backend over its stdin pipe.
+    pub fn send_command(&self, msg: &serde_json::Value) -> Result<()> {
+        let framed = Self::frame_message(msg)?;
+        let mut processes = self.processes.lock().unwrap();
+        let process = processes
+            .last_mut()
+            .ok_or_else(|| anyhow!("No backend process running"))?;
+        process
+            .write(&framed)
+            .context("Failed to write control message to backend")
+    }
+
+    /// Recognize a `{"event": ...}` control response from the backend's stdout.
+    /// Returns `true` if the line was consumed as a framed response (and so shouldn't
+    /// also be written to the log file), `false` for ordinary log output.
+    ///
+    /// Note: `CommandEvent::Stdout` already hands us line-buffered text, so unlike the
+    /// stdin direction (true length-prefixed binary framing via `send_command`), this
+    /// side just recognizes JSON-object lines rather than re-parsing length prefixes.
+    fn handle_framed_line(&self, line: &str) -> bool {
+        let Some((event, value)) = Self::parse_control_event(line) else {
+            return false;
+        };
+
+        match event.as_str() {
+            "bye" => {
+                log::info!("Backend acknowledged shutdown");
+                if let Some(tx) = self.bye_ack.lock().unwrap().take() {
+                    let _ = tx.send(());
+                }
+            }
+            "ready" => log::info!("Backend reports ready: {}", value),
+            "pong" => log::info!("Backend replied to status ping"),
+            _ => log::info!("Backend control event: {}", value),
+        }
+
+        true
+    }
+
+    /// Parse a stdout line as a `{"event": ...}` control response, returning the event
+    /// name alongside the full parsed value. Pulled out of `handle_framed_line` as a
+    /// pure function so the recognition logic can be unit tested without a running
+    /// backend process.
+    fn parse_control_event(line: &str) -> Option<(String, serde_json::Value)> {
+        let value = serde_json::from_str::<serde_json::Value>(line).ok()?;
+        let event = value.get("event")?.as_str()?.to_string();
+        Some((event, value))
+    }
+
+    /// Resolve an externally provided backend base URL, if remote mode is configured,
+    /// preferring `VALUECELL_REMOTE_BACKEND_URL` and falling back to the
+    /// `remoteBackendUrl` settings-store key so it can be toggled from the GUI.
+    fn resolve_remote_backend_url(app: &AppHandle) -> Option<String> {
+        let from_env = std::env::var(REMOTE_BACKEND_URL_ENV)
+            .ok()
+            .filter(|url| !url.trim().is_empty());
+
+        let from_store = || {
+            app.store(SETTINGS_STORE_FILE)
+                .ok()?
+                .get(REMOTE_BACKEND_URL_KEY)?
+                .as_str()
+                .filter(|url| !url.trim().is_empty())
+                .map(str::to_string)
+        };
+
+        from_env.or_else(from_store).map(|url| url.trim().trim_end_matches('/').to_string())
+    }
+
     pub fn new(app: AppHandle) -> Result<Self> {
+        let remote_url = Self::resolve_remote_backend_url(&app);
+
         let resource_root = app
             .path()
             .resolve(".", BaseDirectory::Resource)
             .context("Failed to resolve resource root")?;
 
         let backend_path = resource_root.join("backend");
-        if !backend_path.exists() {
+        if remote_url.is_none() && !backend_path.exists() {
             return Err(anyhow!("Backend directory not found at {:?}", backend_path));
         }
 
@@ -167,6 +368,9 @@ impl BackendManager {
 
         log::info!("Backend path: {:?}", backend_path);
         log::info!("Log directory: {:?}", log_dir);
+        if let Some(url) = &remote_url {
+            log::info!("Remote backend mode configured: {}", url);
+        }
 
         Ok(Self {
             processes: Mutex::new(Vec::new()),
@@ -174,9 +378,27 @@ impl BackendManager {
             log_dir,
             app,
             port: Mutex::new(None),
+            shutdown: AtomicBool::new(false),
+            restart_attempts: AtomicU32::new(0),
+            last_spawn: Mutex::new(Instant::now()),
+            bye_ack: Mutex::new(None),
+            remote_url,
+            status: Mutex::new(BackendStatus::Installing),
         })
     }
 
+    /// Get the current backend lifecycle state.
+    pub fn get_status(&self) -> BackendStatus {
+        *self.status.lock().unwrap()
+    }
+
+    /// Record a lifecycle transition and notify the frontend.
+    fn set_status(&self, status: BackendStatus) {
+        *self.status.lock().unwrap() = status;
+        log::info!("Backend status -> {:?}", status);
+        let _ = self.app.emit("backend-status", status);
+    }
+
     /// Get the system config directory path (must match Python's get_system_env_dir)
     fn get_system_config_dir() -> PathBuf {
         #[cfg(target_os = "macos")]
@@ -240,13 +462,27 @@ impl BackendManager {
         ))
     }
 
-    /// Get the backend port (if discovered)
+    /// Get the backend port (if discovered). For a remote backend this is parsed out
+    /// of the configured URL, since there's no local port file to poll: only a port at
+    /// the very end of the URL (e.g. `http://127.0.0.1:9000`) is recognized, so a URL
+    /// with a path after the port or with no explicit port yields `None`.
     pub fn get_port(&self) -> Option<u16> {
-        *self.port.lock().unwrap()
+        if let Some(port) = *self.port.lock().unwrap() {
+            return Some(port);
+        }
+
+        self.remote_url
+            .as_ref()
+            .and_then(|url| url.rsplit(':').next())
+            .and_then(|segment| segment.parse::<u16>().ok())
     }
 
     /// Get the backend URL
     pub fn get_backend_url(&self) -> Option<String> {
+        if let Some(url) = &self.remote_url {
+            return Some(url.clone());
+        }
+
         self.get_port()
             .map(|port| format!("http://127.0.0.1:{}", port))
     }
@@ -316,13 +552,194 @@ impl BackendManager {
     }
 
     pub fn start_all(&self) -> Result<()> {
-        self.install_dependencies()?;
+        if let Some(url) = self.remote_url.clone() {
+            log::info!("Remote backend mode, skipping local sidecar spawn");
+            self.set_status(BackendStatus::ProbingHealth);
+            return match self.verify_remote_backend(&url) {
+                Ok(()) => {
+                    self.set_status(BackendStatus::Ready);
+                    Ok(())
+                }
+                Err(e) => {
+                    self.set_status(BackendStatus::Failed);
+                    Err(e)
+                }
+            };
+        }
+
+        self.set_status(BackendStatus::Installing);
+        if let Err(e) = self.install_dependencies() {
+            self.set_status(BackendStatus::Failed);
+            return Err(e);
+        }
 
         // Remove stale port file before starting
         let _ = fs::remove_file(Self::get_port_file_path());
 
+        self.spawn_and_register()?;
+        self.start_dev_watcher();
+        Ok(())
+    }
+
+    /// Confirm an externally provided backend is actually reachable before reporting
+    /// startup as successful, reusing the same blocking `reqwest` client pattern as
+    /// `decide_index_url`.
+    fn verify_remote_backend(&self, base_url: &str) -> Result<()> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(REMOTE_PROBE_TIMEOUT_SECS))
+            .build()
+            .context("Failed to create HTTP client for remote backend probe")?;
+
+        let health_url = format!("{}{}", base_url, HEALTH_CHECK_PATH);
+        let response = client
+            .get(&health_url)
+            .send()
+            .with_context(|| format!("Remote backend at {} is not reachable", health_url))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Remote backend at {} returned status {}",
+                health_url,
+                response.status()
+            ));
+        }
+
+        log::info!("Remote backend at {} is reachable", base_url);
+        Ok(())
+    }
+
+    /// Opt-in development mode: watch `backend_path` for `.py` changes and restart the
+    /// backend automatically, so contributors iterating on the Python side don't have to
+    /// quit and relaunch the Tauri shell. No-op unless `VALUECELL_DEV_HOT_RELOAD` is set,
+    /// so release builds never pay for the watcher.
+    fn start_dev_watcher(&self) {
+        if std::env::var(DEV_HOT_RELOAD_ENV).is_err() {
+            return;
+        }
+
+        log::info!(
+            "Dev hot-reload enabled, watching {:?} for .py changes",
+            self.backend_path
+        );
+
+        let backend_path = self.backend_path.clone();
+        let app = self.app.clone();
+
+        std::thread::spawn(move || {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut debouncer =
+                match new_debouncer(Duration::from_millis(DEV_WATCH_DEBOUNCE_MS), tx) {
+                    Ok(debouncer) => debouncer,
+                    Err(e) => {
+                        log::error!("Failed to start dev hot-reload watcher: {e}");
+                        return;
+                    }
+                };
+
+            if let Err(e) = debouncer
+                .watcher()
+                .watch(&backend_path, RecursiveMode::Recursive)
+            {
+                log::error!("Failed to watch {:?} for changes: {e}", backend_path);
+                return;
+            }
+
+            for result in rx {
+                let touched_py = matches!(
+                    &result,
+                    Ok(events) if events
+                        .iter()
+                        .any(|e| e.path.extension().is_some_and(|ext| ext == "py"))
+                );
+                if !touched_py {
+                    continue;
+                }
+
+                log::info!("Detected backend source change, reloading backend...");
+                if let Some(manager) = app.try_state::<BackendManager>() {
+                    manager.reload_backend();
+                }
+            }
+        });
+    }
+
+    /// Kill the running backend and spawn a fresh one in its place, for the dev watcher.
+    /// Holds `processes` for the whole swap so a reload and `stop_all` can't race.
+    fn reload_backend(&self) {
         let mut processes = self.processes.lock().unwrap();
 
+        // Mark this kill as intentional, the same way `stop_all` does, so the
+        // crash-restart supervisor (chunk0-1) doesn't race this reload with a
+        // restart of its own once it sees the old process's `Terminated` event.
+        self.shutdown.store(true, Ordering::SeqCst);
+
+        if let Some(process) = processes.pop() {
+            self.request_graceful_then_kill(process);
+        }
+
+        let _ = fs::remove_file(Self::get_port_file_path());
+
+        self.set_status(BackendStatus::Spawning);
+        match self.spawn_backend_process() {
+            Ok((rx, child)) => {
+                self.stream_backend_logs(rx);
+                log::info!("Process {} added to process list", child.pid());
+                processes.push(child);
+            }
+            Err(e) => {
+                log::error!("Failed to respawn backend during hot reload: {e:#}");
+                self.set_status(BackendStatus::Failed);
+                *self.port.lock().unwrap() = None;
+                self.shutdown.store(false, Ordering::SeqCst);
+                return;
+            }
+        }
+
+        // The replacement is registered and running; re-arm crash detection for it
+        // while we still hold `processes`, so `stop_all` can't interleave between the
+        // swap and the re-arm and have its own `shutdown = true` clobbered right back
+        // to `false` by this call.
+        self.shutdown.store(false, Ordering::SeqCst);
+        drop(processes);
+        *self.last_spawn.lock().unwrap() = Instant::now();
+
+        self.set_status(BackendStatus::WaitingForPort);
+        let port = match self.wait_for_port_file() {
+            Ok(port) => port,
+            Err(e) => {
+                log::error!("Failed to discover backend port after reload: {e:#}");
+                self.set_status(BackendStatus::Failed);
+                *self.port.lock().unwrap() = None;
+                return;
+            }
+        };
+
+        self.set_status(BackendStatus::ProbingHealth);
+        if let Err(e) = self.probe_health(port) {
+            log::error!("Backend did not become healthy after reload: {e:#}");
+            self.set_status(BackendStatus::Failed);
+            *self.port.lock().unwrap() = None;
+            return;
+        }
+
+        *self.port.lock().unwrap() = Some(port);
+        log::info!("Backend reloaded on port {}", port);
+        self.set_status(BackendStatus::Ready);
+        let _ = self.app.emit("backend-restarted", Some(port));
+    }
+
+    /// Spawn the backend process, register it, and wait for it to become reachable.
+    ///
+    /// Shared by the initial `start_all` and by the crash-restart supervisor.
+    fn spawn_and_register(&self) -> Result<()> {
+        self.set_status(BackendStatus::Spawning);
+        let mut processes = self.processes.lock().unwrap();
+
+        // Drop any stale (already-dead) handle left behind by a prior crash before
+        // registering the replacement, so `stop_all`/`Drop` don't also run the full
+        // graceful-then-kill sequence against processes that are already gone.
+        processes.clear();
+
         match self.spawn_backend_process() {
             Ok((rx, child)) => {
                 self.stream_backend_logs(rx);
@@ -331,6 +748,8 @@ impl BackendManager {
             }
             Err(e) => {
                 log::error!("Failed to start backend server: {}", e);
+                self.set_status(BackendStatus::Failed);
+                *self.port.lock().unwrap() = None;
                 return Err(e);
             }
         }
@@ -338,35 +757,206 @@ impl BackendManager {
         // Release lock before waiting for port file
         drop(processes);
 
-        // Wait for port file and store the discovered port
-        match self.wait_for_port_file() {
-            Ok(port) => {
-                *self.port.lock().unwrap() = Some(port);
-                log::info!("Backend started on port {}", port);
-            }
+        *self.last_spawn.lock().unwrap() = Instant::now();
+
+        self.set_status(BackendStatus::WaitingForPort);
+        let port = match self.wait_for_port_file() {
+            Ok(port) => port,
             Err(e) => {
                 log::error!("Failed to discover backend port: {}", e);
+                self.set_status(BackendStatus::Failed);
+                *self.port.lock().unwrap() = None;
                 return Err(e);
             }
+        };
+
+        self.set_status(BackendStatus::ProbingHealth);
+        if let Err(e) = self.probe_health(port) {
+            log::error!("Backend did not become healthy: {}", e);
+            self.set_status(BackendStatus::Failed);
+            *self.port.lock().unwrap() = None;
+            return Err(e);
         }
 
+        *self.port.lock().unwrap() = Some(port);
+        log::info!("Backend started on port {}", port);
+        self.set_status(BackendStatus::Ready);
         Ok(())
     }
 
+    /// Poll the backend's health endpoint until it returns 2xx or the timeout elapses.
+    /// Proves the backend is actually serving requests, not just that it wrote its port
+    /// file, so the frontend doesn't connect during startup and see connection-refused.
+    fn probe_health(&self, port: u16) -> Result<()> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_millis(HEALTH_REQUEST_TIMEOUT_MS))
+            .build()
+            .context("Failed to create HTTP client for health probe")?;
+
+        let url = format!("http://127.0.0.1:{}{}", port, HEALTH_CHECK_PATH);
+        let deadline = std::time::Instant::now() + Duration::from_millis(HEALTH_TIMEOUT_MS);
+
+        log::info!("Waiting for backend health check at {}...", url);
+
+        while std::time::Instant::now() < deadline {
+            if let Ok(response) = client.get(&url).send() {
+                if response.status().is_success() {
+                    log::info!("Backend health check passed");
+                    return Ok(());
+                }
+            }
+            std::thread::sleep(Duration::from_millis(HEALTH_POLL_MS));
+        }
+
+        Err(anyhow!(
+            "Backend did not pass health check at {} within {}ms",
+            url,
+            HEALTH_TIMEOUT_MS
+        ))
+    }
+
     /// Stop all backend processes
     pub fn stop_all(&self) {
+        if self.remote_url.is_some() {
+            log::info!("Remote backend mode, nothing to stop locally");
+            return;
+        }
+
+        // Tell the supervisor this exit is intentional before we start killing anything,
+        // so it doesn't race a `Terminated` event into a restart.
+        self.shutdown.store(true, Ordering::SeqCst);
+
         let mut processes = self.processes.lock().unwrap();
         for process in processes.drain(..) {
             self.request_graceful_then_kill(process);
         }
     }
 
+    /// Handle an unexpected backend exit: apply the restart backoff policy and,
+    /// unless we're shutting down or out of attempts, respawn the process.
+    fn handle_unexpected_exit(&self) {
+        if self.shutdown.load(Ordering::SeqCst) {
+            log::info!("Backend exit was expected (shutdown in progress), not restarting");
+            return;
+        }
+
+        if self.last_spawn.lock().unwrap().elapsed() >= Duration::from_secs(RESTART_STABLE_UPTIME_SECS)
+        {
+            self.restart_attempts.store(0, Ordering::SeqCst);
+        }
+
+        let attempt = self.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        if attempt > MAX_RESTART_ATTEMPTS {
+            log::error!(
+                "Backend crashed {} times in a row, giving up on automatic restart",
+                attempt - 1
+            );
+            *self.port.lock().unwrap() = None;
+            self.set_status(BackendStatus::Failed);
+            let _ = self.app.emit("backend-restart-failed", attempt - 1);
+            return;
+        }
+
+        let delay = Self::restart_backoff(attempt);
+        log::warn!(
+            "Backend exited unexpectedly, restarting in {:?} (attempt {}/{})",
+            delay,
+            attempt,
+            MAX_RESTART_ATTEMPTS
+        );
+        std::thread::sleep(delay);
+
+        if self.shutdown.load(Ordering::SeqCst) {
+            log::info!("Shutdown requested during restart backoff, aborting restart");
+            return;
+        }
+
+        let _ = fs::remove_file(Self::get_port_file_path());
+        match self.spawn_and_register() {
+            Ok(()) => {
+                log::info!("Backend restarted successfully on attempt {}", attempt);
+                let _ = self.app.emit("backend-restarted", self.get_port());
+            }
+            Err(e) => log::error!("Failed to restart backend: {e:#}"),
+        }
+    }
+
+    /// Exponential backoff with a cap and jitter, modeled on CI-runner retry policies:
+    /// `INITIAL_RESTART_BACKOFF_SECS * 2^(attempt - 1)`, capped, plus up to half the
+    /// capped delay in jitter so concurrent crashes don't retry in lockstep.
+    fn restart_backoff(attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let base_secs = INITIAL_RESTART_BACKOFF_SECS.saturating_mul(1u64 << exponent);
+        let capped_secs = base_secs.min(MAX_RESTART_BACKOFF_SECS);
+        let jitter_secs = Self::cheap_jitter(capped_secs / 2);
+        Duration::from_secs(capped_secs + jitter_secs)
+    }
+
+    /// A dependency-free jitter source (nanosecond clock skew is plenty random for
+    /// spreading out retries, and isn't worth pulling in a `rand` dependency for).
+    fn cheap_jitter(max_secs: u64) -> u64 {
+        if max_secs == 0 {
+            return 0;
+        }
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() as u64)
+            .unwrap_or(0);
+        nanos % max_secs
+    }
+
+    /// Directory backend logs are written to, for the `get_log_path`/`open_log_dir`
+    /// Tauri commands.
+    pub fn get_log_dir(&self) -> PathBuf {
+        self.log_dir.clone()
+    }
+
+    /// Classify a log line by its leading `INFO`/`WARN`/`ERROR` token, if present.
+    fn classify_log_level(line: &str) -> LogLevel {
+        let token = line
+            .trim_start()
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .trim_end_matches(':')
+            .to_uppercase();
+
+        match token.as_str() {
+            "ERROR" => LogLevel::Error,
+            "WARN" | "WARNING" => LogLevel::Warn,
+            _ => LogLevel::Info,
+        }
+    }
+
+    /// Rotate `backend.log` -> `backend.log.1`, shifting older generations up and
+    /// dropping whatever falls off the end, so the log doesn't grow forever.
+    fn rotate_log(log_path: &Path) {
+        let oldest = Self::rotated_log_path(log_path, LOG_ROTATE_MAX_GENERATIONS);
+        let _ = fs::remove_file(&oldest);
+
+        for generation in (1..LOG_ROTATE_MAX_GENERATIONS).rev() {
+            let from = Self::rotated_log_path(log_path, generation);
+            if from.exists() {
+                let _ = fs::rename(&from, Self::rotated_log_path(log_path, generation + 1));
+            }
+        }
+
+        let _ = fs::rename(log_path, Self::rotated_log_path(log_path, 1));
+    }
+
+    fn rotated_log_path(log_path: &Path, generation: u32) -> PathBuf {
+        let mut name = log_path.to_path_buf().into_os_string();
+        name.push(format!(".{}", generation));
+        PathBuf::from(name)
+    }
+
     fn stream_backend_logs(&self, rx: Receiver<CommandEvent>) {
         let log_path = self.log_dir.join("backend.log");
-        std::thread::spawn(move || Self::stream_to_file(rx, log_path));
+        let app = self.app.clone();
+        std::thread::spawn(move || Self::stream_to_file(app, rx, log_path));
     }
 
-    fn stream_to_file(mut rx: Receiver<CommandEvent>, log_path: PathBuf) {
+    fn stream_to_file(app: AppHandle, mut rx: Receiver<CommandEvent>, log_path: PathBuf) {
         let mut file = match OpenOptions::new().create(true).append(true).open(&log_path) {
             Ok(file) => file,
             Err(err) => {
@@ -374,15 +964,49 @@ impl BackendManager {
                 return;
             }
         };
+        let mut size = file.metadata().map(|m| m.len()).unwrap_or(0);
 
         while let Some(event) = rx.blocking_recv() {
             match event {
                 CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
                     let text = String::from_utf8_lossy(&line);
-                    if let Err(err) = writeln!(file, "{}", text.trim_end_matches('\n')) {
+                    let trimmed = text.trim_end_matches('\n');
+
+                    let consumed = app
+                        .try_state::<BackendManager>()
+                        .is_some_and(|manager| manager.handle_framed_line(trimmed));
+                    if consumed {
+                        continue;
+                    }
+
+                    let entry = format!("{}\n", trimmed);
+                    if size + entry.len() as u64 > LOG_ROTATE_MAX_BYTES {
+                        drop(file);
+                        Self::rotate_log(&log_path);
+                        file = match OpenOptions::new().create(true).append(true).open(&log_path)
+                        {
+                            Ok(f) => f,
+                            Err(err) => {
+                                log::error!("Failed to reopen backend log after rotation: {}", err);
+                                break;
+                            }
+                        };
+                        size = 0;
+                    }
+
+                    if let Err(err) = file.write_all(entry.as_bytes()) {
                         log::error!("Failed to write backend log line: {}", err);
                         break;
                     }
+                    size += entry.len() as u64;
+
+                    let _ = app.emit(
+                        "backend-log",
+                        BackendLogLine {
+                            level: Self::classify_log_level(trimmed),
+                            line: trimmed.to_string(),
+                        },
+                    );
                 }
                 CommandEvent::Error(err) => {
                     log::error!("Backend process error: {}", err);
@@ -394,6 +1018,9 @@ impl BackendManager {
                         payload.code,
                         payload.signal
                     );
+                    if let Some(manager) = app.try_state::<BackendManager>() {
+                        manager.handle_unexpected_exit();
+                    }
                     break;
                 }
                 _ => {}
@@ -407,3 +1034,81 @@ impl Drop for BackendManager {
         self.stop_all();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restart_backoff_first_attempt_is_roughly_the_initial_delay() {
+        let delay = BackendManager::restart_backoff(1);
+        let min = Duration::from_secs(INITIAL_RESTART_BACKOFF_SECS);
+        let max = Duration::from_secs(INITIAL_RESTART_BACKOFF_SECS + INITIAL_RESTART_BACKOFF_SECS / 2);
+        assert!(
+            delay >= min && delay <= max,
+            "expected {:?}..={:?}, got {:?}",
+            min,
+            max,
+            delay
+        );
+    }
+
+    #[test]
+    fn restart_backoff_caps_at_max_plus_jitter() {
+        let delay = BackendManager::restart_backoff(MAX_RESTART_ATTEMPTS);
+        let max = Duration::from_secs(MAX_RESTART_BACKOFF_SECS + MAX_RESTART_BACKOFF_SECS / 2);
+        assert!(delay.as_secs() >= MAX_RESTART_BACKOFF_SECS);
+        assert!(delay <= max, "expected <= {:?}, got {:?}", max, delay);
+    }
+
+    #[test]
+    fn restart_backoff_does_not_grow_past_the_cap_for_further_attempts() {
+        let capped = BackendManager::restart_backoff(MAX_RESTART_ATTEMPTS);
+        let way_past_cap = BackendManager::restart_backoff(MAX_RESTART_ATTEMPTS + 20);
+        let max = Duration::from_secs(MAX_RESTART_BACKOFF_SECS + MAX_RESTART_BACKOFF_SECS / 2);
+        assert!(capped <= max);
+        assert!(way_past_cap <= max);
+    }
+
+    #[test]
+    fn cheap_jitter_stays_within_bound() {
+        for _ in 0..10 {
+            assert!(BackendManager::cheap_jitter(10) < 10);
+        }
+        assert_eq!(BackendManager::cheap_jitter(0), 0);
+    }
+
+    #[test]
+    fn frame_message_round_trips_through_parse_control_event() {
+        let msg = serde_json::json!({"event": "ready", "port": 1234});
+        let framed = BackendManager::frame_message(&msg).unwrap();
+
+        let len = u32::from_be_bytes(framed[..4].try_into().unwrap()) as usize;
+        let body = std::str::from_utf8(&framed[4..4 + len]).unwrap();
+
+        let (event, value) = BackendManager::parse_control_event(body).unwrap();
+        assert_eq!(event, "ready");
+        assert_eq!(value["port"], 1234);
+    }
+
+    #[test]
+    fn parse_control_event_rejects_non_json_and_eventless_lines() {
+        assert!(BackendManager::parse_control_event("not json").is_none());
+        assert!(BackendManager::parse_control_event(r#"{"no_event": true}"#).is_none());
+    }
+
+    #[test]
+    fn classify_log_level_recognizes_leading_tokens() {
+        assert_eq!(BackendManager::classify_log_level("ERROR: boom"), LogLevel::Error);
+        assert_eq!(BackendManager::classify_log_level("WARN something"), LogLevel::Warn);
+        assert_eq!(BackendManager::classify_log_level("WARNING: something"), LogLevel::Warn);
+        assert_eq!(BackendManager::classify_log_level("INFO all good"), LogLevel::Info);
+        assert_eq!(BackendManager::classify_log_level("no leading token"), LogLevel::Info);
+    }
+
+    #[test]
+    fn rotated_log_path_appends_generation_suffix() {
+        let path = BackendManager::rotated_log_path(Path::new("/tmp/backend.log"), 2);
+        assert_eq!(path, PathBuf::from("/tmp/backend.log.2"));
+    }
+}
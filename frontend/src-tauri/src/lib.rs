@@ -1,7 +1,8 @@
 mod backend;
 
-use backend::BackendManager;
-use tauri::{Manager, State};
+use backend::{BackendManager, BackendStatus};
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_opener::OpenerExt;
 
 /// Get the backend URL that the frontend should connect to
 #[tauri::command]
@@ -15,6 +16,28 @@ fn get_backend_port(manager: State<BackendManager>) -> Option<u16> {
     manager.get_port()
 }
 
+/// Get the current backend lifecycle state, so the UI can show an accurate
+/// splash/loading screen and distinguish "still starting" from "crashed"
+#[tauri::command]
+fn get_backend_status(manager: State<BackendManager>) -> BackendStatus {
+    manager.get_status()
+}
+
+/// Get the path to the current backend log file, so the UI can link to it
+#[tauri::command]
+fn get_log_path(manager: State<BackendManager>) -> String {
+    manager.get_log_dir().join("backend.log").to_string_lossy().into_owned()
+}
+
+/// Open the backend log directory in the system file manager
+#[tauri::command]
+fn open_log_dir(manager: State<BackendManager>, app: AppHandle) -> Result<(), String> {
+    let log_dir = manager.get_log_dir();
+    app.opener()
+        .open_path(log_dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -54,7 +77,13 @@ pub fn run() {
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![get_backend_url, get_backend_port])
+        .invoke_handler(tauri::generate_handler![
+            get_backend_url,
+            get_backend_port,
+            get_backend_status,
+            get_log_path,
+            open_log_dir
+        ])
         .on_window_event(|window, event| {
             // Handle window close events to ensure proper cleanup
             if let tauri::WindowEvent::Destroyed = event {